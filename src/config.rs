@@ -0,0 +1,112 @@
+use std::{fs, path::PathBuf};
+
+use once_cell::sync::{Lazy, OnceCell};
+use serde::Deserialize;
+
+/// The set of reports to show as tabs, loaded once at startup.
+pub static CONFIG: Lazy<Config> = Lazy::new(|| Config::load(config_path_override().flatten()));
+
+static CONFIG_PATH_OVERRIDE: OnceCell<Option<PathBuf>> = OnceCell::new();
+
+/// Record the `--config` path (if any) before `CONFIG` is first dereferenced.
+///
+/// Must be called before anything touches `CONFIG`; later calls are ignored.
+pub fn set_config_path_override(path: Option<PathBuf>) {
+    let _ = CONFIG_PATH_OVERRIDE.set(path);
+}
+
+fn config_path_override() -> Option<Option<PathBuf>> {
+    CONFIG_PATH_OVERRIDE.get().cloned()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TabConfig {
+    pub name: String,
+    pub command: String,
+    #[serde(default = "default_color")]
+    pub color: bool,
+}
+
+fn default_color() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub tabs: Vec<TabConfig>,
+    /// Wrap URLs in report bodies as clickable OSC 8 terminal hyperlinks.
+    #[serde(default = "default_true")]
+    pub hyperlinks: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            tabs: vec![
+                TabConfig {
+                    name: "Due".into(),
+                    command: "project.not:Bethany due".into(),
+                    color: true,
+                },
+                TabConfig {
+                    name: "Active".into(),
+                    command: "project.not:Bethany active".into(),
+                    color: false,
+                },
+                TabConfig {
+                    name: "Inbox".into(),
+                    command: "-PROJECT".into(),
+                    color: true,
+                },
+            ],
+            hyperlinks: true,
+        }
+    }
+}
+
+impl Config {
+    fn load(override_path: Option<PathBuf>) -> Self {
+        let explicit = override_path.is_some();
+        let Some(path) = override_path.or_else(default_config_path) else {
+            return Config::default();
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            // No config file at the default location is expected; anywhere
+            // else (an explicit --config path) it's worth a note.
+            Err(err) => {
+                if explicit {
+                    eprintln!(
+                        "taskwarrior-summary: ignoring {}: {err}",
+                        path.display()
+                    );
+                }
+                return Config::default();
+            }
+        };
+
+        match toml::from_str(&contents) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!(
+                    "taskwarrior-summary: ignoring {}: {err}",
+                    path.display()
+                );
+                Config::default()
+            }
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/taskwarrior-summary/config.toml`, falling back to `~/.config`.
+fn default_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("taskwarrior-summary").join("config.toml"))
+}
@@ -0,0 +1,110 @@
+//! OSC 8 terminal hyperlinks for `http(s)://` URLs found in report text.
+//!
+//! `ansi_to_tui` parses SGR colour codes into `Style`s but has no concept of
+//! a hyperlink, and ratatui's `Buffer`/`Cell` model has nowhere to carry one
+//! either — so OSC 8 escapes can't be pre-baked into the text handed to
+//! `into_text()`. Instead the functions here locate URLs and hand back their
+//! position/text so the caller can write the escape sequence directly to
+//! the terminal, out of band from the normal ratatui draw.
+
+const OSC8_START: &str = "\x1b]8;;";
+const OSC8_MID: &str = "\x1b\\";
+const OSC8_END: &str = "\x1b]8;;\x1b\\";
+
+/// Every `http(s)://` URL in `input`, as `(char_offset, url)` pairs, for
+/// callers that need to know *where* a URL is rather than just its text.
+pub fn find_urls(input: &str) -> Vec<(usize, String)> {
+    let mut urls = Vec::new();
+    let mut rest = input;
+    let mut chars_consumed = 0;
+
+    while let Some(start) = find_url_start(rest) {
+        chars_consumed += rest[..start].chars().count();
+
+        let candidate = &rest[start..];
+        let end = url_end(candidate);
+        urls.push((chars_consumed, candidate[..end].to_string()));
+
+        chars_consumed += candidate[..end].chars().count();
+        rest = &candidate[end..];
+    }
+    urls
+}
+
+/// The OSC 8 escape sequence that marks `url` as a hyperlink over itself.
+pub fn wrap_url(url: &str) -> String {
+    format!("{OSC8_START}{url}{OSC8_MID}{url}{OSC8_END}")
+}
+
+fn find_url_start(s: &str) -> Option<usize> {
+    match (s.find("https://"), s.find("http://")) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (Some(a), None) => Some(a),
+        (None, Some(b)) => Some(b),
+        (None, None) => None,
+    }
+}
+
+fn url_end(s: &str) -> usize {
+    s.find(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == '\x1b')
+        .unwrap_or(s.len())
+}
+
+/// Best-effort check for whether the attached terminal renders OSC 8 links.
+///
+/// Degrades to `false` for terminals known not to support it (e.g. VS Code's
+/// integrated terminal) or when there's no terminal info to go on at all.
+pub fn terminal_supports_osc8() -> bool {
+    if std::env::var("TERM_PROGRAM").as_deref() == Ok("vscode") {
+        return false;
+    }
+
+    !matches!(std::env::var("TERM").as_deref(), Ok("") | Ok("dumb") | Err(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_urls_locates_a_bare_url() {
+        let found = find_urls("see https://example.com/x for details");
+        assert_eq!(found, vec![(4, "https://example.com/x".to_string())]);
+    }
+
+    #[test]
+    fn find_urls_handles_adjacent_urls() {
+        let found = find_urls("https://a.test https://b.test");
+        assert_eq!(
+            found,
+            vec![(0, "https://a.test".to_string()), (15, "https://b.test".to_string())]
+        );
+    }
+
+    #[test]
+    fn find_urls_stops_at_sgr_reset_with_no_whitespace() {
+        // Taskwarrior often colors the whole field, so a reset sequence can
+        // butt right up against the URL with no separating whitespace.
+        let input = "\x1b[31mhttps://example.com/ticket/1\x1b[0m";
+        let found = find_urls(input);
+        assert_eq!(found, vec![(5, "https://example.com/ticket/1".to_string())]);
+    }
+
+    #[test]
+    fn find_urls_handles_an_unterminated_url_at_eof() {
+        let found = find_urls("ref: https://example.com/eof");
+        assert_eq!(found, vec![(5, "https://example.com/eof".to_string())]);
+    }
+
+    #[test]
+    fn find_urls_reports_char_offsets_not_byte_offsets() {
+        // A multi-byte prefix means char offset and byte offset diverge.
+        let found = find_urls("café https://example.com/x");
+        assert_eq!(found, vec![(5, "https://example.com/x".to_string())]);
+    }
+
+    #[test]
+    fn find_urls_returns_empty_for_no_urls() {
+        assert!(find_urls("nothing to see").is_empty());
+    }
+}
@@ -1,31 +1,45 @@
-use std::{collections::HashMap, io::stdout, time::Duration};
+use std::{cell::RefCell, collections::HashMap, io::stdout, path::PathBuf, time::Duration};
 
 use color_eyre::Result;
 
 use ansi_to_tui::IntoText;
 use crossterm::{
+    cursor::MoveTo,
     event::{DisableMouseCapture, EnableMouseCapture, MouseEvent, MouseEventKind},
     execute,
+    style::Print,
 };
 use clap::Parser;
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
-    layout::{Constraint, Layout, Position, Rect},
-    style::{palette::tailwind, Color, Stylize},
-    widgets::{Block, Padding, Paragraph, StatefulWidget, Tabs, Widget},
+    layout::{Constraint, Flex, Layout, Position, Rect},
+    style::{palette::tailwind, Color, Modifier, Style, Stylize},
+    widgets::{Block, Clear, Padding, Paragraph, StatefulWidget, Tabs, Widget},
     DefaultTerminal,
 };
-use strum::{Display, EnumIter, FromRepr, IntoEnumIterator};
-
-use strum_macros;
-// bring the trait into scope
-use strum::EnumProperty;
 
+mod config;
+mod hyperlinks;
 mod taskwarrior;
 
+use config::{TabConfig, CONFIG};
+
+/// Lines scrolled per key press or mouse wheel tick.
+const SCROLL_STEP: u16 = 1;
+/// Lines scrolled per PageUp/PageDown press.
+const PAGE_SCROLL_STEP: u16 = 10;
+/// Blank lines kept below the last line of a report when fully scrolled down.
+const SCROLL_BOTTOM_PADDING: u16 = 2;
+/// Reports have a header line, so the first selectable row is row 1.
+const DATA_START_ROW: usize = 1;
+
 fn main() -> Result<()> {
     color_eyre::install()?;
+
+    let args = Cli::parse();
+    config::set_config_path_override(args.config);
+
     let terminal = ratatui::init();
 
     // Setup mouse capture events
@@ -42,20 +56,54 @@ fn main() -> Result<()> {
 // Use clap to parse arguments and specify possible values
 #[derive(Parser)]
 struct Cli {
-    #[arg(value_enum)]
-    tab: SelectedTab,
+    /// Path to a TOML config file listing the reports to show as tabs.
+    ///
+    /// Defaults to `$XDG_CONFIG_HOME/taskwarrior-summary/config.toml`.
+    #[arg(long)]
+    config: Option<PathBuf>,
 }
 
-
 #[derive(Default)]
 struct App {
     app_state: AppState,
-    selected_tab: SelectedTab,
-
-    reports: HashMap<SelectedTab, String>,
+    selected_tab: usize,
+
+    layout_mode: LayoutMode,
+    /// The tab shown in each pane while `layout_mode` is `Split`.
+    split_tabs: [usize; 2],
+    /// Which split pane the selection cursor and scroll keys target.
+    focused_pane: usize,
+
+    reports: HashMap<usize, String>,
+    scroll_offsets: HashMap<usize, u16>,
+    selected_rows: HashMap<usize, usize>,
+    /// Rendered content height of each tab's most recent pane, recorded by
+    /// `render_report_pane` so scroll clamping matches what's actually on
+    /// screen (unbordered in `Tabbed`, bordered in `Split`).
+    pane_heights: RefCell<HashMap<usize, u16>>,
+    /// Screen positions of the URLs rendered in the current frame, recorded
+    /// by `render_report_pane` and written out to the terminal after the
+    /// ratatui draw call (see `write_hyperlinks`).
+    hyperlink_spans: RefCell<Vec<HyperlinkSpan>>,
+    pending_action: Option<PendingAction>,
     pub event: Option<MouseEvent>,
 }
 
+/// Where a URL landed on screen, so its OSC 8 escape can be written directly
+/// to the terminal after the fact.
+///
+/// `ansi_to_tui` parses SGR colour codes into `Style`s but has no concept of
+/// a hyperlink, and ratatui's `Buffer`/`Cell` model has nowhere to carry one
+/// either — so OSC 8 escapes can't be pre-baked into the text handed to
+/// `into_text()` and drawn through ratatui. Instead `render_report_pane`
+/// records where each URL ended up, and `write_hyperlinks` prints the
+/// escape sequence straight to stdout once the draw is done.
+struct HyperlinkSpan {
+    x: u16,
+    y: u16,
+    url: String,
+}
+
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 enum AppState {
     #[default]
@@ -63,43 +111,29 @@ enum AppState {
     Quitting,
 }
 
-#[derive(
-    Default,
-    Clone,
-    Copy,
-    Display,
-    FromRepr,
-    EnumIter,
-    strum_macros::EnumProperty,
-    PartialEq,
-    Eq,
-    Hash,
-    clap::ValueEnum,
-)]
-enum SelectedTab {
+/// Whether the body shows the selected tab alone or two tabs side by side.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum LayoutMode {
     #[default]
-    #[strum(to_string = "Due", props(cmd = "project.not:Bethany due"))]
-    Due,
-    #[strum(
-        to_string = "Active",
-        props(cmd = "project.not:Bethany active", Color = "false")
-    )]
-    Active,
-    #[strum(to_string = "Inbox", props(cmd = "-PROJECT"))]
-    Inbox,
+    Tabbed,
+    Split,
+}
+
+/// A mutating Taskwarrior command awaiting `y`/`n` confirmation.
+enum PendingAction {
+    Delete { task_id: String },
 }
 
 impl App {
     fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
         self.reload_reports();
 
-        let args = Cli::parse();
-        
         while self.app_state == AppState::Running {
             self.handle_events()?;
-            let mut new_state = args.tab;
+            let mut new_state = self.selected_tab;
             terminal
                 .draw(|frame| frame.render_stateful_widget(&self, frame.area(), &mut new_state))?;
+            self.write_hyperlinks()?;
             self.selected_tab = new_state;
             self.event = None;
         }
@@ -115,20 +149,52 @@ impl App {
             return Ok(())
         }
 
-        match event::read()? {
+        let event = event::read()?;
+
+        if let Some(action) = self.pending_action.take() {
+            // Only 'y' fires the action; any other key or mouse event
+            // cancels it instead of leaking through to the report
+            // underneath (e.g. a click switching tabs, or a wheel scroll).
+            if let Event::Key(key) = &event {
+                if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('y') {
+                    self.execute_pending_action(action);
+                }
+            }
+            return Ok(());
+        }
+
+        match event {
             Event::Key(key) => {
-                if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('l') | KeyCode::Right => self.next_tab(),
-                        KeyCode::Char('h') | KeyCode::Left => self.previous_tab(),
-                        KeyCode::Char('q') | KeyCode::Esc => self.quit(),
-                        _ => {}
-                    }
+                if key.kind != KeyEventKind::Press {
+                    return Ok(());
+                }
+
+                match key.code {
+                    KeyCode::Char('l') | KeyCode::Right => self.next_tab(),
+                    KeyCode::Char('h') | KeyCode::Left => self.previous_tab(),
+                    KeyCode::Char('j') => self.scroll_down(SCROLL_STEP),
+                    KeyCode::Char('k') => self.scroll_up(SCROLL_STEP),
+                    KeyCode::Down => self.move_selection_down(),
+                    KeyCode::Up => self.move_selection_up(),
+                    KeyCode::PageDown => self.scroll_down(PAGE_SCROLL_STEP),
+                    KeyCode::PageUp => self.scroll_up(PAGE_SCROLL_STEP),
+                    KeyCode::Char('d') => self.mark_selected_done(),
+                    KeyCode::Char('s') => self.toggle_selected_start_stop(),
+                    KeyCode::Char('x') => self.request_delete_selected(),
+                    KeyCode::Char('v') => self.toggle_layout_mode(),
+                    KeyCode::Tab => self.toggle_focused_pane(),
+                    KeyCode::Char('q') | KeyCode::Esc => self.quit(),
+                    _ => {}
                 }
             }
             Event::Mouse(mouse) => {
-                if mouse.kind == MouseEventKind::Down(event::MouseButton::Left) {
-                    self.event = Some(mouse);
+                match mouse.kind {
+                    MouseEventKind::Down(event::MouseButton::Left) => {
+                        self.event = Some(mouse);
+                    }
+                    MouseEventKind::ScrollDown => self.scroll_down(SCROLL_STEP),
+                    MouseEventKind::ScrollUp => self.scroll_up(SCROLL_STEP),
+                    _ => {}
                 }
             }
             _ => (),
@@ -138,29 +204,22 @@ impl App {
     }
 
     fn reload_reports(&mut self) {
-        // Will run each report and store the result
-        for tab in SelectedTab::iter() {
-            let cmd = tab.get_str("cmd").expect("Enum expected command");
-            let color_str = tab.get_str("Color").unwrap_or("true");
-            let mut color = true;
-            if color_str == "false" {
-                color = false;
-            }
-
-            let output = taskwarrior::run(color, String::from(cmd).split(' '));
+        // Will run each configured tab's report and store the result
+        for (index, tab) in CONFIG.tabs.iter().enumerate() {
+            let output = taskwarrior::run(tab.color, tab.command.split(' '));
             let output = output.expect("Expected TaskWarrior cmd to have a result");
 
-            self.reports.insert(tab, output);
+            self.reports.insert(index, output);
         }
     }
 
-    pub fn mouse_cord_to_tab(&self, pos: Position) -> Option<SelectedTab> {
+    pub fn mouse_cord_to_tab(&self, pos: Position) -> Option<usize> {
         let mut offset = 0;
-        for tab in SelectedTab::iter() {
-            let report = self.reports.get(&tab).unwrap();
-            let width = tab.title(report).len() as u16;
+        for (index, tab) in CONFIG.tabs.iter().enumerate() {
+            let report = self.reports.get(&index).unwrap();
+            let width = tab_title(tab, report).len() as u16;
             if pos.x < offset + width {
-                return Some(tab);
+                return Some(index);
             }
             offset += width;
         }
@@ -169,42 +228,180 @@ impl App {
     }
 
     pub fn next_tab(&mut self) {
-        self.selected_tab = self.selected_tab.next();
+        let last_index = CONFIG.tabs.len().saturating_sub(1);
+        let next = self.active_tab().saturating_add(1).min(last_index);
+        self.set_active_tab(next);
     }
 
     pub fn previous_tab(&mut self) {
-        self.selected_tab = self.selected_tab.previous();
+        let previous = self.active_tab().saturating_sub(1);
+        self.set_active_tab(previous);
     }
 
     pub fn quit(&mut self) {
         self.app_state = AppState::Quitting;
     }
-}
 
-impl SelectedTab {
-    /// Get the previous tab, if there is no previous tab return the current tab.
-    fn previous(self) -> Self {
-        let current_index: usize = self as usize;
-        let previous_index = current_index.saturating_sub(1);
-        Self::from_repr(previous_index).unwrap_or(self)
+    /// The tab the selection cursor and scroll keys currently target: the
+    /// selected tab in `Tabbed` mode, or the focused pane's tab in `Split`.
+    fn active_tab(&self) -> usize {
+        match self.layout_mode {
+            LayoutMode::Tabbed => self.selected_tab,
+            LayoutMode::Split => self.split_tabs[self.focused_pane],
+        }
+    }
+
+    fn set_active_tab(&mut self, tab: usize) {
+        match self.layout_mode {
+            LayoutMode::Tabbed => self.selected_tab = tab,
+            LayoutMode::Split => self.split_tabs[self.focused_pane] = tab,
+        }
+    }
+
+    pub fn toggle_layout_mode(&mut self) {
+        self.layout_mode = match self.layout_mode {
+            LayoutMode::Tabbed => {
+                if self.split_tabs[0] == self.split_tabs[1] {
+                    let tab_count = CONFIG.tabs.len().max(1);
+                    self.split_tabs[1] = (self.split_tabs[1] + 1) % tab_count;
+                }
+                LayoutMode::Split
+            }
+            LayoutMode::Split => LayoutMode::Tabbed,
+        };
+    }
+
+    pub fn toggle_focused_pane(&mut self) {
+        if self.layout_mode == LayoutMode::Split {
+            self.focused_pane = 1 - self.focused_pane;
+        }
     }
 
-    /// Get the next tab, if there is no next tab return the current tab.
-    fn next(self) -> Self {
-        let current_index = self as usize;
-        let next_index = current_index.saturating_add(1);
-        Self::from_repr(next_index).unwrap_or(self)
+    pub fn scroll_up(&mut self, amount: u16) {
+        let offset = self.scroll_offsets.entry(self.active_tab()).or_insert(0);
+        *offset = offset.saturating_sub(amount);
     }
 
-    fn title(self, report: &String) -> String {
-        return format!(" {} ({}) ", self, taskwarrior::task_count(report));
+    pub fn scroll_down(&mut self, amount: u16) {
+        let max_offset = self.max_scroll(self.active_tab());
+        let offset = self.scroll_offsets.entry(self.active_tab()).or_insert(0);
+        *offset = offset.saturating_add(amount).min(max_offset);
     }
+
+    /// The furthest a tab's report can be scrolled down, leaving
+    /// `SCROLL_BOTTOM_PADDING` blank lines below the last line of content.
+    fn max_scroll(&self, tab: usize) -> u16 {
+        let Some(report) = self.reports.get(&tab) else {
+            return 0;
+        };
+        let line_count = report.lines().count() as u16;
+        // Until the tab has been rendered at least once there's no pane
+        // height to clamp against, so don't allow scrolling yet.
+        let visible_height = self
+            .pane_heights
+            .borrow()
+            .get(&tab)
+            .copied()
+            .unwrap_or(u16::MAX);
+        (line_count + SCROLL_BOTTOM_PADDING).saturating_sub(visible_height)
+    }
+
+    pub fn move_selection_up(&mut self) {
+        let row = self
+            .selected_rows
+            .entry(self.active_tab())
+            .or_insert(DATA_START_ROW);
+        *row = row.saturating_sub(1).max(DATA_START_ROW);
+    }
+
+    pub fn move_selection_down(&mut self) {
+        let max_row = self.max_row(self.active_tab());
+        let row = self
+            .selected_rows
+            .entry(self.active_tab())
+            .or_insert(DATA_START_ROW);
+        *row = row.saturating_add(1).min(max_row);
+    }
+
+    fn max_row(&self, tab: usize) -> usize {
+        self.reports
+            .get(&tab)
+            .map_or(DATA_START_ROW, |report| report.lines().count().saturating_sub(1))
+    }
+
+    /// The Taskwarrior ID of the task on the currently selected row, if any.
+    fn selected_task_id(&self) -> Option<String> {
+        let report = self.reports.get(&self.active_tab())?;
+        let row = *self
+            .selected_rows
+            .get(&self.active_tab())
+            .unwrap_or(&DATA_START_ROW);
+        let line = report.lines().nth(row)?;
+        let plain_line = taskwarrior::strip_ansi(line);
+        let id = plain_line.split_whitespace().next()?.to_string();
+        id.chars().all(|c| c.is_ascii_digit()).then_some(id)
+    }
+
+    pub fn mark_selected_done(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            self.run_task_command([task_id, "done".into()]);
+        }
+    }
+
+    pub fn toggle_selected_start_stop(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            let verb = if taskwarrior::task_is_active(&task_id) { "stop" } else { "start" };
+            self.run_task_command([task_id, verb.into()]);
+        }
+    }
+
+    pub fn request_delete_selected(&mut self) {
+        if let Some(task_id) = self.selected_task_id() {
+            self.pending_action = Some(PendingAction::Delete { task_id });
+        }
+    }
+
+    fn execute_pending_action(&mut self, action: PendingAction) {
+        match action {
+            PendingAction::Delete { task_id } => {
+                self.run_task_command([task_id, "delete".into(), "rc.confirmation:no".into()]);
+            }
+        }
+    }
+
+    fn run_task_command(&mut self, args: impl IntoIterator<Item = String>) {
+        if let Err(err) = taskwarrior::run(true, args) {
+            eprintln!("Error running task command: {err}");
+        }
+        self.reload_reports();
+    }
+
+    /// Print the OSC 8 escape for each URL recorded by `render_report_pane`
+    /// directly to the terminal, out of band from the ratatui draw that just
+    /// happened. This is the only way the hyperlinks actually reach the
+    /// terminal; see the note on `HyperlinkSpan`.
+    fn write_hyperlinks(&self) -> std::io::Result<()> {
+        for span in self.hyperlink_spans.borrow().iter() {
+            execute!(
+                stdout(),
+                MoveTo(span.x, span.y),
+                Print(hyperlinks::wrap_url(&span.url))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn tab_title(tab: &TabConfig, report: &str) -> String {
+    format!(" {} ({}) ", tab.name, taskwarrior::task_count(report))
 }
 
 impl StatefulWidget for &App {
-    type State = SelectedTab;
+    type State = usize;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut usize) {
+        self.hyperlink_spans.borrow_mut().clear();
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut SelectedTab) {
         use Constraint::{Length, Min};
         let vertical = Layout::vertical([Length(1), Min(0)]);
         let [header_area, inner_area] = vertical.areas(area);
@@ -228,34 +425,164 @@ impl StatefulWidget for &App {
             _ => {}
         }
 
-        // Get the main body output for the tab
-        let tab_output = self
-            .reports
-            .get(&self.selected_tab)
-            .expect("Cmd result expected");
-        let text = tab_output.into_text().unwrap();
+        match self.layout_mode {
+            LayoutMode::Tabbed => {
+                self.render_report_pane(self.selected_tab, inner_area, buf, None);
+            }
+            LayoutMode::Split => {
+                let [left_area, right_area] = Layout::horizontal([
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ])
+                .areas(inner_area);
+                self.render_report_pane(
+                    self.split_tabs[0],
+                    left_area,
+                    buf,
+                    Some(self.focused_pane == 0),
+                );
+                self.render_report_pane(
+                    self.split_tabs[1],
+                    right_area,
+                    buf,
+                    Some(self.focused_pane == 1),
+                );
+            }
+        }
+
+        if let Some(action) = &self.pending_action {
+            render_confirm_overlay(area, buf, action);
+        }
+    }
+}
+
+impl App {
+    /// Render one tab's report into `area`.
+    ///
+    /// `active_border` is `None` for the plain `Tabbed` layout (no border),
+    /// or `Some(is_active)` in `Split` mode to draw a border that's
+    /// highlighted for the focused pane.
+    fn render_report_pane(&self, tab: usize, area: Rect, buf: &mut Buffer, active_border: Option<bool>) {
+        let Some(tab_output) = self.reports.get(&tab) else {
+            return;
+        };
+
+        // Padding::uniform(1) always costs 1 cell on each side; a bordered
+        // block costs 1 more on each side.
+        let border_width = if active_border.is_some() { 1 } else { 0 };
+        let content_x = area.x + 1 + border_width;
+        let content_y = area.y + 1 + border_width;
+        let content_width = area.width.saturating_sub(2 * (1 + border_width));
+        let content_height = area.height.saturating_sub(2 * (1 + border_width));
+        self.pane_heights.borrow_mut().insert(tab, content_height);
+
+        let mut text = tab_output.into_text().unwrap();
+
+        let scroll_offset = *self.scroll_offsets.get(&tab).unwrap_or(&0);
+        if CONFIG.hyperlinks && hyperlinks::terminal_supports_osc8() {
+            self.record_hyperlink_spans(
+                tab_output,
+                scroll_offset,
+                content_x,
+                content_y,
+                content_width,
+                content_height,
+            );
+        }
+
+        let selected_row = *self.selected_rows.get(&tab).unwrap_or(&DATA_START_ROW);
+        if let Some(line) = text.lines.get_mut(selected_row) {
+            *line = std::mem::take(line).patch_style(Style::new().add_modifier(Modifier::REVERSED));
+        }
+
+        let block = match active_border {
+            None => Block::new().padding(Padding::uniform(1)),
+            Some(is_active) => Block::bordered()
+                .padding(Padding::uniform(1))
+                .border_style(Style::new().fg(if is_active {
+                    tailwind::SLATE.c300
+                } else {
+                    tailwind::SLATE.c800
+                })),
+        };
+
         Paragraph::new(text)
-            .block(Block::new().padding(Padding::uniform(1)))
-            .render(inner_area, buf);
+            .scroll((scroll_offset, 0))
+            .block(block)
+            .render(area, buf);
     }
+
+    /// Find the URLs visible in `tab_output` given the current scroll
+    /// position and record their on-screen coordinates in
+    /// `self.hyperlink_spans`, clipped to the pane's content rect.
+    fn record_hyperlink_spans(
+        &self,
+        tab_output: &str,
+        scroll_offset: u16,
+        content_x: u16,
+        content_y: u16,
+        content_width: u16,
+        content_height: u16,
+    ) {
+        let mut spans = self.hyperlink_spans.borrow_mut();
+        for (line_index, line) in tab_output.lines().enumerate() {
+            if line_index < scroll_offset as usize {
+                continue;
+            }
+            let row_in_pane = line_index - scroll_offset as usize;
+            if row_in_pane >= content_height as usize {
+                break;
+            }
+
+            let plain_line = taskwarrior::strip_ansi(line);
+            for (char_offset, url) in hyperlinks::find_urls(&plain_line) {
+                let char_offset = char_offset as u16;
+                if char_offset >= content_width {
+                    continue;
+                }
+                let visible_width = (content_width - char_offset) as usize;
+                let visible_url: String = url.chars().take(visible_width).collect();
+                spans.push(HyperlinkSpan {
+                    x: content_x + char_offset,
+                    y: content_y + row_in_pane as u16,
+                    url: visible_url,
+                });
+            }
+        }
+    }
+}
+
+fn render_confirm_overlay(area: Rect, buf: &mut Buffer, action: &PendingAction) {
+    let message = match action {
+        PendingAction::Delete { task_id } => format!("Delete task {task_id}? (y/n)"),
+    };
+
+    let vertical = Layout::vertical([Constraint::Length(3)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Length(40)]).flex(Flex::Center);
+    let [popup_area] = vertical.areas(area);
+    let [popup_area] = horizontal.areas(popup_area);
+
+    Clear.render(popup_area, buf);
+    Paragraph::new(message)
+        .centered()
+        .block(Block::bordered().title(" Confirm "))
+        .render(popup_area, buf);
 }
 
 impl App {
     fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
-        let titles = SelectedTab::iter().map(|t| {
-            let tab_output = self.reports.get(&t).expect("Expected report for enum");
+        let titles = CONFIG.tabs.iter().enumerate().map(|(index, tab)| {
+            let tab_output = self.reports.get(&index).expect("Expected report for tab");
 
-            t.title(tab_output)
+            tab_title(tab, tab_output)
                 .fg(tailwind::SLATE.c600)
                 .bg(Color::default())
         });
 
-        // let titles = SelectedTab::iter().map(SelectedTab::title);
         let highlight_style = (Color::default(), Color::default());
-        let selected_tab_index = self.selected_tab as usize;
         let tabs = Tabs::new(titles)
             .highlight_style(highlight_style)
-            .select(selected_tab_index)
+            .select(self.selected_tab)
             .padding("", "")
             .divider("");
 
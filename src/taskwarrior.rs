@@ -22,3 +22,65 @@ where
 pub fn task_count(report: &String) -> usize {
     return report.lines().count() - 1;
 }
+
+/// Whether a task is currently started (has an open `start` timestamp), as
+/// opposed to merely pending, used to decide which way a start/stop toggle goes.
+pub fn task_is_active(id: &str) -> bool {
+    run(false, ["export", id])
+        .map(|export| export.contains("\"start\":"))
+        .unwrap_or(false)
+}
+
+/// Strip ANSI CSI escape sequences (e.g. `\x1b[31m`) from coloured Taskwarrior output.
+pub fn strip_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.clone().next() == Some('[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+            continue;
+        }
+        output.push(c);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_ansi_removes_a_colored_id() {
+        assert_eq!(strip_ansi("\x1b[31m12\x1b[0m Overdue task"), "12 Overdue task");
+    }
+
+    #[test]
+    fn strip_ansi_leaves_plain_text_untouched() {
+        assert_eq!(strip_ansi("12 Plain task"), "12 Plain task");
+    }
+
+    #[test]
+    fn strip_ansi_handles_back_to_back_sequences() {
+        assert_eq!(strip_ansi("\x1b[1m\x1b[31m12\x1b[0m"), "12");
+    }
+
+    #[test]
+    fn strip_ansi_handles_an_unterminated_escape_at_eof() {
+        // Malformed/truncated input shouldn't panic; the dangling escape is dropped.
+        assert_eq!(strip_ansi("12\x1b[3"), "12");
+    }
+
+    #[test]
+    fn selected_row_id_extraction_survives_colored_rows() {
+        let line = "\x1b[31m12 Overdue task project:Home\x1b[0m";
+        let id = strip_ansi(line).split_whitespace().next().unwrap().to_string();
+        assert_eq!(id, "12");
+    }
+}